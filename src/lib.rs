@@ -27,7 +27,7 @@ pub use crate::cal::datetime::{LocalDate, LocalTime, LocalDateTime, Month, Weekd
 pub use crate::cal::fmt::custom as fmt;
 pub use crate::cal::fmt::ISO;  // TODO: replace this with just a 'fmt' import
 pub use crate::cal::offset::{Offset, OffsetDateTime};
-pub use crate::cal::zone::{TimeZone, ZonedDateTime};
+pub use crate::cal::zone::{LocalResult, TimeZone, ZonedDateTime};
 pub use crate::cal::zone as zone;
 
 pub use crate::cal::convenience;
@@ -42,3 +42,5 @@ mod system;
 pub use crate::system::sys_timezone;
 
 mod util;
+
+mod tz;
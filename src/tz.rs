@@ -0,0 +1,208 @@
+//! A parser for the binary TZif format used by `/etc/localtime` and the
+//! system zoneinfo database (see `cal::zone::TimeZone::zoneinfo`).
+//!
+//! This supports both the legacy 32-bit-timestamp v1 format, and the
+//! v2/v3 extensions: a second, 64-bit-timestamp copy of the data (which is
+//! the copy we actually keep), and a trailing POSIX `TZ` string describing
+//! how to keep computing offsets past the last recorded transition.
+
+use std::error::Error as StdError;
+use std::fmt;
+
+
+/// The local time type in effect during a `Transition`: an offset from
+/// UTC, plus whether it counts as daylight-saving time.
+#[derive(Clone, Debug)]
+pub struct LocalTimeType {
+    pub offset: i32,
+    pub is_dst: bool,
+    pub name: String,
+}
+
+/// A single UTC instant at which a zone's offset changes, and the type
+/// that applies from that instant on.
+#[derive(Clone, Debug)]
+pub struct Transition {
+    pub timestamp: i32,
+    pub local_time_type: LocalTimeType,
+}
+
+/// Everything decoded from a TZif file that `TimeZone::zoneinfo` needs.
+#[derive(Clone, Debug)]
+pub struct TZData {
+    pub transitions: Vec<Transition>,
+
+    /// The trailing POSIX `TZ` string from a v2/v3 file's footer, such as
+    /// `EST5EDT,M3.2.0,M11.1.0`. `None` for v1 files, and for v2/v3 files
+    /// that simply don't have one.
+    pub footer: Option<String>,
+}
+
+/// An error produced while parsing a TZif file.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid TZif data: {}", self.0)
+    }
+}
+
+impl StdError for Error {
+    fn description(&self) -> &str {
+        "invalid TZif data"
+    }
+}
+
+fn fail(message: &str) -> Error {
+    Error(message.to_owned())
+}
+
+
+/// The fixed 44-byte header at the start of a TZif file, and again at the
+/// start of its v2/v3 64-bit-timestamp block.
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    (u32::from(bytes[0]) << 24) | (u32::from(bytes[1]) << 16) | (u32::from(bytes[2]) << 8) | u32::from(bytes[3])
+}
+
+fn read_i32(bytes: &[u8]) -> i32 {
+    read_u32(bytes) as i32
+}
+
+fn read_i64(bytes: &[u8]) -> i64 {
+    let mut value: u64 = 0;
+    for &byte in &bytes[.. 8] {
+        value = (value << 8) | u64::from(byte);
+    }
+    value as i64
+}
+
+/// Parses the 44-byte header found at the start of a TZif file, and again
+/// (identically shaped) at the start of its v2/v3 block.
+fn parse_header(input: &[u8]) -> Result<Header, Error> {
+    if input.len() < 44 {
+        return Err(fail("file is shorter than the TZif header"));
+    }
+
+    if &input[0 .. 4] != b"TZif" {
+        return Err(fail("missing 'TZif' magic number"));
+    }
+
+    Ok(Header {
+        version:  input[4],
+        isutcnt:  read_u32(&input[20 .. 24]) as usize,
+        isstdcnt: read_u32(&input[24 .. 28]) as usize,
+        leapcnt:  read_u32(&input[28 .. 32]) as usize,
+        timecnt:  read_u32(&input[32 .. 36]) as usize,
+        typecnt:  read_u32(&input[36 .. 40]) as usize,
+        charcnt:  read_u32(&input[40 .. 44]) as usize,
+    })
+}
+
+/// Reads the local time type records and their abbreviation strings, which
+/// are shared between the v1 and v2/v3 data blocks.
+fn parse_local_time_types(header: &Header, input: &[u8]) -> Result<Vec<LocalTimeType>, Error> {
+    let abbrev_start = header.typecnt * 6;
+    let abbrevs = try!(input.get(abbrev_start .. abbrev_start + header.charcnt)
+                       .ok_or_else(|| fail("truncated abbreviation string")));
+
+    (0 .. header.typecnt).map(|i| {
+        let record = &input[i * 6 .. i * 6 + 6];
+        let offset = read_i32(&record[0 .. 4]);
+        let is_dst = record[4] != 0;
+        let abbrev_index = record[5] as usize;
+
+        let name_bytes = try!(abbrevs.get(abbrev_index ..)
+                                 .ok_or_else(|| fail("abbreviation index out of range")));
+        let name_end = try!(name_bytes.iter().position(|&b| b == 0)
+                                 .ok_or_else(|| fail("unterminated abbreviation")));
+        let name = String::from_utf8_lossy(&name_bytes[.. name_end]).into_owned();
+
+        Ok(LocalTimeType { offset: offset, is_dst: is_dst, name: name })
+    }).collect()
+}
+
+/// Parses one data block: `timecnt` transition times (either 4 or 8 bytes
+/// wide), their type indices, the local time types themselves, and the
+/// leap-second/indicator tables that follow (which we don't otherwise use).
+/// Returns the parsed transitions and the total size of the block in bytes.
+fn parse_data_block(header: &Header, input: &[u8], time_width: usize) -> Result<(Vec<Transition>, usize), Error> {
+    let times_end    = header.timecnt * time_width;
+    let types_end    = times_end + header.timecnt;
+    let ttinfo_end   = types_end + header.typecnt * 6;
+    let abbrev_end   = ttinfo_end + header.charcnt;
+    let leap_width   = if time_width == 8 { 12 } else { 8 };
+    let leap_end     = abbrev_end + header.leapcnt * leap_width;
+    let isstd_end    = leap_end + header.isstdcnt;
+    let isut_end     = isstd_end + header.isutcnt;
+
+    let block = try!(input.get(.. isut_end).ok_or_else(|| fail("truncated transition data")));
+
+    let local_time_types = try!(parse_local_time_types(header, &block[types_end .. abbrev_end]));
+
+    let transitions = (0 .. header.timecnt).map(|i| {
+        let timestamp = if time_width == 8 {
+            let wide = read_i64(&block[i * 8 .. i * 8 + 8]);
+            if wide > i64::from(i32::max_value()) { i32::max_value() }
+            else if wide < i64::from(i32::min_value()) { i32::min_value() }
+            else { wide as i32 }
+        }
+        else {
+            read_i32(&block[i * 4 .. i * 4 + 4])
+        };
+
+        let type_index = block[times_end + i] as usize;
+        let local_time_type = try!(local_time_types.get(type_index)
+                                               .ok_or_else(|| fail("transition type index out of range")))
+                                               .clone();
+
+        Ok(Transition { timestamp: timestamp, local_time_type: local_time_type })
+    }).collect::<Result<Vec<_>, Error>>();
+    let transitions = try!(transitions);
+
+    Ok((transitions, isut_end))
+}
+
+/// Parses the contents of a TZif file (as read from `/etc/localtime` or a
+/// zoneinfo database entry) into its transitions and, if present, its
+/// trailing POSIX `TZ` footer string.
+pub fn parse(contents: Vec<u8>) -> Result<TZData, Error> {
+    let header = try!(parse_header(&contents));
+    let (v1_transitions, v1_len) = try!(parse_data_block(&header, &contents[44 ..], 4));
+
+    if header.version == 0 {
+        return Ok(TZData { transitions: v1_transitions, footer: None });
+    }
+
+    // v2/v3 files repeat the header and data block with 8-byte (64-bit)
+    // transition times, which is the copy we keep. `Transition` stores
+    // timestamps as `i32`, so ones outside that range are saturated to it.
+    let v2_header_start = 44 + v1_len;
+    let v2_header = try!(parse_header(&contents[v2_header_start ..]));
+    let v2_data_start = v2_header_start + 44;
+    let (v2_transitions, v2_len) = try!(parse_data_block(&v2_header, &contents[v2_data_start ..], 8));
+
+    let footer_start = v2_data_start + v2_len;
+    let footer = match contents.get(footer_start ..) {
+        Some(rest) if rest.first() == Some(&b'\n')  => {
+            let rest = &rest[1 ..];
+            match rest.iter().position(|&b| b == b'\n') {
+                Some(end)  => Some(String::from_utf8_lossy(&rest[.. end]).into_owned()).filter(|s| !s.is_empty()),
+                None       => None,
+            }
+        },
+        _ => None,
+    };
+
+    Ok(TZData { transitions: v2_transitions, footer: footer })
+}
@@ -5,24 +5,71 @@ use local::ParseError as LocalParseError;
 use parse;
 use util::RangeExt;
 
+use std::env;
 use std::error::Error as StdError;
 use std::fs::File;
 use std::io::Read;
 use std::num::ParseIntError;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use duration::Duration;
 use tz::{Transition, parse};
 
 
+/// The usual places a system's tz database lives, searched in order when
+/// `$TZDIR` isn't set. See `TimeZone::from_zone_name`.
+const ZONEINFO_DIRECTORIES: &[&str] = &[
+    "/usr/share/zoneinfo",
+    "/usr/lib/zoneinfo",
+    "/etc/zoneinfo",
+];
+
+/// The result of resolving a wall-clock `LocalDateTime` against a
+/// `TimeZone`, returned by `TimeZone::resolve`.
+///
+/// A wall-clock time during a spring-forward gap doesn't correspond to any
+/// real instant, and one during a fall-back overlap corresponds to two.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum LocalResult<T> {
+    /// The local time falls in a gap created by a spring-forward
+    /// transition, and doesn't exist.
+    None,
+
+    /// The local time unambiguously corresponds to one instant.
+    Single(T),
+
+    /// The local time falls in the overlap created by a fall-back
+    /// transition, and could be either of these two instants, earlier one
+    /// first.
+    Ambiguous(T, T),
+}
+
 /// A **time zone** is used to calculate how much to adjust a UTC-based time
 /// based on its geographical location.
 #[derive(Clone, Debug)]
 pub enum TimeZone {
     UTC,
-    FixedOffset { offset: i32 },
-    VariableOffset { transitions: Vec<Transition> }
+
+    /// A fixed offset from UTC, in seconds.
+    ///
+    /// `unknown` marks the RFC 2822 `-00:00` case: the offset is *unknown*
+    /// rather than genuinely zero, even though it's computed as UTC either
+    /// way. Round-tripping one of these back out should render `-00:00`
+    /// rather than `Z`. Use `TimeZone::unknown_offset` to construct one.
+    FixedOffset { offset: i32, unknown: bool },
+
+    /// A time zone backed by a zoneinfo (TZif) transition table.
+    ///
+    /// `transitions` is always kept sorted in *ascending* order by
+    /// timestamp, so that the transition in effect for a given instant can
+    /// be located with a binary search.
+    ///
+    /// `posix_rule`, when present, comes from the POSIX `TZ` string in the
+    /// footer of a TZif v2/v3 file, and lets `adjust_variable` keep
+    /// computing the correct offset for instants past the last transition
+    /// actually recorded in the file.
+    VariableOffset { transitions: Vec<Transition>, posix_rule: Option<PosixTzRule> }
 }
 
 /// A **time zone** is used to calculate how much to adjust a UTC-based time
@@ -31,8 +78,8 @@ impl TimeZone {
     fn adjust(&self, local: LocalDateTime) -> LocalDateTime {
         match *self {
             TimeZone::UTC                                 => { self.adjust_utc(local) },
-            TimeZone::FixedOffset { offset }              => { self.adjust_fixed(offset, local) },
-            TimeZone::VariableOffset { ref transitions }  => { self.adjust_variable(&transitions, local) },
+            TimeZone::FixedOffset { offset, .. }          => { self.adjust_fixed(offset, local) },
+            TimeZone::VariableOffset { ref transitions, ref posix_rule }  => { self.adjust_variable(transitions, posix_rule.as_ref(), local) },
         }
     }
 
@@ -44,13 +91,71 @@ impl TimeZone {
         local + Duration::of(offset as i64)
     }
 
-    fn adjust_variable(&self, transitions: &Vec<Transition>, local: LocalDateTime) -> LocalDateTime {
+    fn adjust_variable(&self, transitions: &[Transition], posix_rule: Option<&PosixTzRule>, local: LocalDateTime) -> LocalDateTime {
         let unix_timestamp = local.to_instant().seconds() as i32;
+        let offset = variable_offset_at(transitions, posix_rule, local.year(), unix_timestamp);
+        local + Duration::of(offset as i64)
+    }
 
-        // TODO: Replace this with a binary search
-        match transitions.iter().find(|t| t.timestamp < unix_timestamp) {
-            None     => local,
-            Some(t)  => local + Duration::of(t.local_time_type.offset as i64),
+    /// Resolves a wall-clock local time against this time zone, handling
+    /// the cases where it's ambiguous (a fall-back overlap, yielding two
+    /// possible instants) or doesn't exist at all (a spring-forward gap).
+    ///
+    /// `UTC` and `FixedOffset` never have gaps or overlaps, so they always
+    /// return `LocalResult::Single`.
+    pub fn resolve(&self, local: LocalDateTime) -> LocalResult<LocalDateTime> {
+        match *self {
+            TimeZone::UTC                                                 => LocalResult::Single(local),
+            TimeZone::FixedOffset { offset, .. }                          => LocalResult::Single(local + Duration::of(-(offset as i64))),
+            TimeZone::VariableOffset { ref transitions, ref posix_rule }  => self.resolve_variable(transitions, posix_rule.as_ref(), local),
+        }
+    }
+
+    fn resolve_variable(&self, transitions: &[Transition], posix_rule: Option<&PosixTzRule>, local: LocalDateTime) -> LocalResult<LocalDateTime> {
+        let wall_timestamp = local.to_instant().seconds() as i32;
+        let year = local.year();
+
+        // Gather the offsets that are plausibly in effect around this wall
+        // time: the ones either side of the transition(s) nearest to it,
+        // plus whatever the POSIX footer rule says for this instant.
+        let mut candidate_offsets = Vec::new();
+        let push = |offset: i32, candidates: &mut Vec<i32>| if !candidates.contains(&offset) { candidates.push(offset); };
+
+        match transitions.binary_search_by(|t| t.timestamp.cmp(&wall_timestamp)) {
+            Ok(index) => {
+                push(transitions[index].local_time_type.offset, &mut candidate_offsets);
+                if index > 0 { push(transitions[index - 1].local_time_type.offset, &mut candidate_offsets); }
+            },
+            Err(0) => push(transitions.first().map_or(0, |t| t.local_time_type.offset), &mut candidate_offsets),
+            Err(index) => {
+                push(transitions[index - 1].local_time_type.offset, &mut candidate_offsets);
+                if index > 1 { push(transitions[index - 2].local_time_type.offset, &mut candidate_offsets); }
+                if index < transitions.len() { push(transitions[index].local_time_type.offset, &mut candidate_offsets); }
+            },
+        }
+
+        if let Some(rule) = posix_rule {
+            push(rule.offset_at(year, wall_timestamp), &mut candidate_offsets);
+        }
+
+        // A candidate offset is only genuinely valid if the instant it
+        // implies is one where that offset is *actually* in effect — this
+        // is what rules out the offset that doesn't apply in a gap, and
+        // confirms both offsets that do apply in an overlap.
+        let mut instants: Vec<i32> = candidate_offsets.into_iter()
+            .map(|offset| wall_timestamp - offset)
+            .filter(|&instant| variable_offset_at(transitions, posix_rule, year_of_timestamp(instant), instant) == wall_timestamp - instant)
+            .collect();
+
+        instants.sort();
+        instants.dedup();
+
+        let to_local = |instant: i32| local + Duration::of((instant - wall_timestamp) as i64);
+
+        match instants.len() {
+            0  => LocalResult::None,
+            1  => LocalResult::Single(to_local(instants[0])),
+            _  => LocalResult::Ambiguous(to_local(instants[0]), to_local(instants[1])),
         }
     }
 
@@ -61,11 +166,31 @@ impl TimeZone {
         }
     }
 
-    /// Read time zone information in from the user's local time zone.
-    pub fn localtime() -> Result<TimeZone, Box<StdError>> {
-        // TODO: replace this with some kind of factory.
-        // this won't be appropriate for all systems
-        TimeZone::zoneinfo(&Path::new("/etc/localtime"))
+    /// Detects the system's local time zone, falling back to `TimeZone::UTC`
+    /// if it can't be determined.
+    ///
+    /// This is the common-case entry point: minimal or containerized
+    /// systems legitimately have no `/etc/localtime` and no `TZ` set, and
+    /// should still run with sensible UTC behaviour rather than erroring
+    /// out. Use `localtime_detect` instead if you need to know whether
+    /// detection actually succeeded.
+    pub fn localtime() -> TimeZone {
+        TimeZone::localtime_detect().unwrap_or(TimeZone::UTC)
+    }
+
+    /// Detects the system's local time zone, returning an error if it
+    /// can't be determined.
+    ///
+    /// Consults the `TZ` environment variable first: an empty value, or
+    /// `UTC`/`Etc/UTC`, resolves to `TimeZone::UTC`; any other value is
+    /// resolved as a zone name via `from_zone_name`. If `TZ` isn't set,
+    /// falls back to reading `/etc/localtime`.
+    pub fn localtime_detect() -> Result<TimeZone, Box<StdError>> {
+        match env::var("TZ") {
+            Ok(ref tz) if tz.is_empty() || tz == "UTC" || tz == "Etc/UTC"  => Ok(TimeZone::UTC),
+            Ok(ref tz)                                                    => TimeZone::from_zone_name(tz),
+            Err(_)                                                        => TimeZone::zoneinfo(&Path::new("/etc/localtime")),
+        }
     }
 
     /// Read time zone information in from the file at the given path,
@@ -77,11 +202,47 @@ impl TimeZone {
         let _bytes_read  = try!(file.read_to_end(&mut contents));
         let mut tz       = try!(parse(contents));
 
-        // Sort the transitions *backwards* to make it easier to get the first
-        // one *after* a specified time.
-        tz.transitions.sort_by(|b, a| a.timestamp.cmp(&b.timestamp));
+        // Keep the transitions sorted *ascending* by timestamp so that
+        // `adjust_variable` (and any future reverse lookup) can binary
+        // search them instead of scanning linearly.
+        tz.transitions.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+
+        // TZif v2/v3 files carry a trailing POSIX `TZ` string describing how
+        // to keep computing offsets for instants past the last transition.
+        // It's optional: v1 files, and some v2/v3 files with no foreseeable
+        // future rule changes, don't have one.
+        let posix_rule = match tz.footer {
+            Some(ref footer)  => Some(try!(PosixTzRule::parse(footer))),
+            None              => None,
+        };
 
-        Ok(TimeZone::VariableOffset { transitions: tz.transitions })
+        Ok(TimeZone::VariableOffset { transitions: tz.transitions, posix_rule: posix_rule })
+    }
+
+    /// Read time zone information in for the named IANA/Olson zone, such as
+    /// `Europe/London` or `America/New_York`, from the system's tz database.
+    ///
+    /// The database directory is `$TZDIR` if that environment variable is
+    /// set; otherwise the usual system locations are searched, in order, for
+    /// the first one that has a file for the zone.
+    /// Returns `Error::UnknownZone` if `name` can't be found there, and
+    /// rejects names that would escape the database directory (a leading
+    /// `/`, or a `..` path component) before ever touching the filesystem.
+    pub fn from_zone_name(name: &str) -> Result<TimeZone, Box<StdError>> {
+        if name.starts_with('/') || name.split('/').any(|part| part == "..") {
+            return Err(Box::new(Error::UnsafeZoneName));
+        }
+
+        let search_dirs: Vec<PathBuf> = match env::var_os("TZDIR") {
+            Some(dir)  => vec![PathBuf::from(dir)],
+            None       => ZONEINFO_DIRECTORIES.iter().map(PathBuf::from).collect(),
+        };
+        let tzdir = search_dirs.into_iter().find(|dir| dir.join(name).is_file());
+
+        match tzdir {
+            Some(dir)  => TimeZone::zoneinfo(&dir.join(name)),
+            None       => Err(Box::new(Error::UnknownZone)),
+        }
     }
 
     /// Create a new fixed-offset timezone with the given number of seconds.
@@ -90,13 +251,27 @@ impl TimeZone {
     /// worth of seconds (86400) in either direction.
     pub fn of_seconds(seconds: i32) -> Result<TimeZone, Error> {
         if seconds.is_within(-86400..86401) {
-            Ok(TimeZone::FixedOffset { offset: seconds })
+            Ok(TimeZone::FixedOffset { offset: seconds, unknown: false })
         }
         else {
             Err(Error::OutOfRange)
         }
     }
 
+    /// Create the "unknown offset" timezone, as used by RFC 2822's
+    /// `-00:00`: computed as UTC, but distinct from it, so that formats
+    /// which can tell the difference (`-00:00` versus `Z`/`+00:00`) can
+    /// round-trip it correctly.
+    pub fn unknown_offset() -> TimeZone {
+        TimeZone::FixedOffset { offset: 0, unknown: true }
+    }
+
+    /// Whether this is the "unknown offset" timezone created by
+    /// `unknown_offset`.
+    pub fn is_unknown_offset(&self) -> bool {
+        matches!(*self, TimeZone::FixedOffset { unknown: true, .. })
+    }
+
     /// Create a new fixed-offset timezone with the given number of hours and
     /// minutes.
     ///
@@ -120,7 +295,7 @@ impl TimeZone {
         else {
             let hours = hours as i32;
             let minutes = minutes as i32;
-            TimeZone::of_seconds(hours * 24 + minutes * 60)
+            TimeZone::of_seconds(hours * 3600 + minutes * 60)
         }
     }
 
@@ -130,6 +305,13 @@ impl TimeZone {
 
         let result = match fields {
             Zulu => return Ok(TimeZone::UTC),
+
+            // RFC 2822's `-00:00`: the offset is *unknown*, not genuinely
+            // zero, even though it's computed the same way as UTC.
+            Offset { sign: "-", hours, minutes } if is_all_zero(hours) && minutes.map_or(true, is_all_zero) => {
+                return Ok(TimeZone::unknown_offset());
+            },
+
             Offset { sign: "+", hours, minutes: None } => TimeZone::of_hours_and_minutes( try!(parse(hours)), 0),
             Offset { sign: "-", hours, minutes: None } => TimeZone::of_hours_and_minutes(-try!(parse(hours)), 0),
             Offset { sign: "+", hours, minutes: Some(mins) } => TimeZone::of_hours_and_minutes( try!(parse(hours)),  try!(parse(mins))),
@@ -139,6 +321,45 @@ impl TimeZone {
 
         result.map_err(ParseError::Zone)
     }
+
+    /// Parses an offset the way `FromStr` does, but permissively: it also
+    /// accepts the looser forms seen in the wild, such as `+09`, `+0930`,
+    /// `+09:30`, and bare `Z`, whether or not a `:` separates the hours and
+    /// minutes, and whether or not minutes are present at all.
+    ///
+    /// The strict `FromStr` implementation goes through `parse_iso_8601_zone`
+    /// and its regex, which doesn't accept all of these; use this instead
+    /// when ingesting timestamps from sources that aren't so strict.
+    pub fn from_str_permissive(input: &str) -> Result<TimeZone, ParseError> {
+        if input.eq_ignore_ascii_case("z") {
+            return Ok(TimeZone::UTC);
+        }
+
+        let mut chars = input.chars();
+        let sign = match chars.next() {
+            Some('+')  => 1,
+            Some('-')  => -1,
+            _          => return Err(ParseError::Malformed(format!("expected 'Z' or a leading sign, found {:?}", input))),
+        };
+
+        // Only `hh`, `hhmm` or `hh:mm` may follow the sign.
+        let rest = chars.as_str();
+        let digits = if rest.len() == 5 && rest.as_bytes()[2] == b':' { format!("{}{}", &rest[.. 2], &rest[3 ..]) } else { rest.to_owned() };
+        if (digits.len() != 2 && digits.len() != 4) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseError::Malformed(format!("expected 2 or 4 offset digits, found {:?}", rest)));
+        }
+
+        let hours: i8 = try!(digits[.. 2].parse().map_err(ParseError::Number));
+        let minutes: i8 = if digits.len() == 4 { try!(digits[2 ..].parse().map_err(ParseError::Number)) } else { 0 };
+
+        TimeZone::of_hours_and_minutes(sign * hours, sign * minutes).map_err(ParseError::Zone)
+    }
+}
+
+/// Whether every character of `s` is `'0'` (and `s` isn't empty), used to
+/// recognise RFC 2822's `-00:00` "unknown offset".
+fn is_all_zero(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c == '0')
 }
 
 impl FromStr for TimeZone {
@@ -152,11 +373,392 @@ impl FromStr for TimeZone {
     }
 }
 
+impl ::std::fmt::Display for TimeZone {
+    /// Renders `UTC` as `Z`, and a fixed offset as `±HH:MM` or (for the RFC
+    /// 2822 "unknown offset" case) `-00:00` — see `TimeZone::unknown_offset`.
+    /// A `VariableOffset` has no single offset to render, so this shows the
+    /// POSIX footer rule's standard-time name if one was recorded, and `Z`
+    /// otherwise.
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            TimeZone::UTC  => write!(f, "Z"),
+
+            TimeZone::FixedOffset { unknown: true, .. }  => write!(f, "-00:00"),
+
+            TimeZone::FixedOffset { offset, .. }  => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let minutes = offset.abs() / 60;
+                write!(f, "{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+            },
+
+            TimeZone::VariableOffset { posix_rule: Some(ref rule), .. }  => write!(f, "{}", rule.std_name),
+            TimeZone::VariableOffset { posix_rule: None, .. }            => write!(f, "Z"),
+        }
+    }
+}
+
+
+/// A day on which a POSIX `TZ` rule switches between standard and
+/// daylight-saving time, in one of the three forms the format allows.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum PosixTzDay {
+    /// `Jn`: the `n`th day of the year, `1` to `365`, with February 29th
+    /// never counted (so this day always lands on the same month and day
+    /// regardless of whether the year is a leap year).
+    Julian(u16),
+
+    /// `n`: the `n`th day of the year, `0` to `365`, with February 29th
+    /// counted in leap years.
+    ZeroBased(u16),
+
+    /// `Mm.w.d`: weekday `d` (`0` is Sunday) of week `w` (`1`-`4`, or `5`
+    /// meaning "the last such weekday") of month `m` (`1`-`12`).
+    MonthWeekDay { month: u8, week: u8, weekday: u8 },
+}
+
+/// One half of a POSIX `TZ` rule: the day it falls on, and the wall-clock
+/// time of day (in seconds, default `02:00:00`) the switch happens at.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct PosixTzTransition {
+    pub day: PosixTzDay,
+    pub time: i32,
+}
+
+impl PosixTzTransition {
+    /// Works out the UTC instant this transition falls on in the given
+    /// year, treating `self.time` as wall-clock time at the given offset.
+    fn instant_in(&self, year: i64, offset_before: i32) -> i32 {
+        let yearday = match self.day {
+            PosixTzDay::Julian(n) => {
+                let n = i64::from(n) - 1;
+                if is_leap_year(year) && n >= 59 { n + 1 } else { n }
+            },
+            PosixTzDay::ZeroBased(n)  => i64::from(n),
+            PosixTzDay::MonthWeekDay { month, week, weekday } => {
+                nth_weekday_of_month(year, month, week, weekday) - days_from_civil(year, 1, 1)
+            },
+        };
+
+        let unix_day = days_from_civil(year, 1, 1) + yearday;
+        (unix_day * 86400 + i64::from(self.time) - i64::from(offset_before)) as i32
+    }
+}
+
+/// The daylight-saving half of a POSIX `TZ` rule: its abbreviation, its
+/// offset from UTC, and the two transitions that switch it on and off.
+#[derive(PartialEq, Debug, Clone)]
+struct PosixTzDst {
+    #[allow(dead_code)]
+    name: String,
+    offset: i32,
+    start: PosixTzTransition,
+    end: PosixTzTransition,
+}
+
+/// A parsed POSIX `TZ` string, such as `EST5EDT,M3.2.0,M11.1.0`, taken from
+/// the footer of a TZif v2/v3 file. This is what `adjust_variable` falls
+/// back on to compute offsets for instants past the last transition that
+/// the file actually recorded.
+#[derive(PartialEq, Debug, Clone)]
+pub struct PosixTzRule {
+    std_name: String,
+    std_offset: i32,
+    dst: Option<PosixTzDst>,
+}
+
+impl PosixTzRule {
+    /// Works out the offset from UTC that applies at the given instant,
+    /// evaluating the start/end transitions for the instant's calendar year.
+    fn offset_at(&self, year: i64, unix_timestamp: i32) -> i32 {
+        let dst = match self.dst {
+            Some(ref dst)  => dst,
+            None           => return self.std_offset,
+        };
+
+        let start = dst.start.instant_in(year, self.std_offset);
+        let end   = dst.end.instant_in(year, dst.offset);
+
+        let in_dst = if start <= end {
+            unix_timestamp >= start && unix_timestamp < end
+        }
+        else {
+            // Southern-hemisphere-style rules have DST active either side
+            // of the new year, so the "start" transition comes later in
+            // the year than the "end" one.
+            unix_timestamp >= start || unix_timestamp < end
+        };
+
+        if in_dst { dst.offset } else { self.std_offset }
+    }
+
+    /// Parses a POSIX `TZ` string such as `EST5EDT,M3.2.0,M11.1.0` or
+    /// `GMT0`, as found in the footer of a TZif v2/v3 file.
+    pub fn parse(input: &str) -> Result<PosixTzRule, PosixTzParseError> {
+        let mut parts = input.splitn(2, ',');
+        let offsets_part = parts.next().unwrap_or("");
+        let rules_part    = parts.next();
+
+        let (std_name, rest) = try!(take_tz_name(offsets_part));
+        let (std_offset, rest) = try!(take_tz_offset(rest));
+        let std_offset = -std_offset;  // POSIX offsets are positive *west* of UTC.
+
+        let dst = if rest.is_empty() {
+            None
+        }
+        else {
+            let (dst_name, rest) = try!(take_tz_name(rest));
+            let (dst_offset, _rest) = if rest.is_empty() || rest.starts_with(',') {
+                (std_offset + 3600, rest)  // Defaults to one hour ahead of standard time.
+            }
+            else {
+                let (offset, rest) = try!(take_tz_offset(rest));
+                (-offset, rest)
+            };
+
+            let rules = try!(rules_part.ok_or_else(|| PosixTzParseError("missing start,end transition rules for DST".into())));
+            let mut rules = rules.splitn(2, ',');
+            let start = try!(parse_tz_transition(try!(rules.next().ok_or_else(|| PosixTzParseError("missing DST start rule".into())))));
+            let end   = try!(parse_tz_transition(try!(rules.next().ok_or_else(|| PosixTzParseError("missing DST end rule".into())))));
+
+            Some(PosixTzDst { name: dst_name.into(), offset: dst_offset, start: start, end: end })
+        };
+
+        Ok(PosixTzRule { std_name: std_name.into(), std_offset: std_offset, dst: dst })
+    }
+}
+
+/// Looks up the offset from UTC that applies at `unix_timestamp`, using the
+/// (ascending-sorted) transition table and falling back to the POSIX `TZ`
+/// footer rule, if there is one, once we're past the last recorded
+/// transition. Shared by `adjust_variable` and `resolve`.
+fn variable_offset_at(transitions: &[Transition], posix_rule: Option<&PosixTzRule>, year: i64, unix_timestamp: i32) -> i32 {
+    if let Some(last) = transitions.last() {
+        if unix_timestamp > last.timestamp {
+            if let Some(rule) = posix_rule {
+                return rule.offset_at(year, unix_timestamp);
+            }
+        }
+    }
+    else if let Some(rule) = posix_rule {
+        return rule.offset_at(year, unix_timestamp);
+    }
+
+    // `transitions` is kept sorted in ascending order by timestamp (see
+    // `zoneinfo`), so the last transition at or before this instant can be
+    // found with a binary search instead of a linear scan.
+    match transitions.binary_search_by(|t| t.timestamp.cmp(&unix_timestamp)) {
+        Ok(index)    => transitions[index].local_time_type.offset,
+        // Instant precedes the earliest transition: fall back to the first
+        // recorded type (LMT), or UTC if there's no transition data at all.
+        Err(0)       => transitions.first().map_or(0, |t| t.local_time_type.offset),
+        Err(index)   => transitions[index - 1].local_time_type.offset,
+    }
+}
+
+/// An error produced when parsing a POSIX `TZ` string fails.
+#[derive(PartialEq, Debug, Clone)]
+pub struct PosixTzParseError(String);
+
+impl ::std::fmt::Display for PosixTzParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "invalid POSIX TZ string: {}", self.0)
+    }
+}
+
+impl StdError for PosixTzParseError {
+    fn description(&self) -> &str {
+        "invalid POSIX TZ string"
+    }
+}
+
+/// Consumes a `TZ` name: either a bare run of letters (`EST`), or a
+/// quoted run of arbitrary characters (`<-05>`).
+fn take_tz_name(input: &str) -> Result<(&str, &str), PosixTzParseError> {
+    if let Some(rest) = input.strip_prefix('<') {
+        match rest.find('>') {
+            Some(end)  => Ok((&rest[.. end], &rest[end + 1 ..])),
+            None       => Err(PosixTzParseError("unterminated <...> TZ name".into())),
+        }
+    }
+    else {
+        let end = input.find(|c: char| !c.is_alphabetic()).unwrap_or(input.len());
+        if end == 0 {
+            Err(PosixTzParseError("expected a TZ name".into()))
+        }
+        else {
+            Ok((&input[.. end], &input[end ..]))
+        }
+    }
+}
+
+/// Consumes a `[+-]hh[:mm[:ss]]` offset, returned in seconds.
+fn take_tz_offset(input: &str) -> Result<(i32, &str), PosixTzParseError> {
+    let (sign, input) = match input.chars().next() {
+        Some('-')  => (-1, &input[1 ..]),
+        Some('+')  => (1, &input[1 ..]),
+        _          => (1, input),
+    };
+
+    let end = input.find(|c: char| !(c.is_ascii_digit() || c == ':')).unwrap_or(input.len());
+    let (field, rest) = (&input[.. end], &input[end ..]);
+
+    let mut components = field.splitn(3, ':');
+    let hours   = try!(parse_tz_int(components.next().unwrap_or("")));
+    let minutes = try!(components.next().map(parse_tz_int).unwrap_or(Ok(0)));
+    let seconds = try!(components.next().map(parse_tz_int).unwrap_or(Ok(0)));
+
+    Ok((sign * (hours * 3600 + minutes * 60 + seconds), rest))
+}
+
+/// Parses one `start` or `end` transition rule, such as `M3.2.0` or
+/// `M3.2.0/2` or `J60` or `45`.
+fn parse_tz_transition(input: &str) -> Result<PosixTzTransition, PosixTzParseError> {
+    let mut parts = input.splitn(2, '/');
+    let day_part  = parts.next().unwrap_or("");
+    let time_part = parts.next();
+
+    let day = if let Some(spec) = day_part.strip_prefix('J') {
+        PosixTzDay::Julian(try!(parse_tz_int(spec)) as u16)
+    }
+    else if let Some(spec) = day_part.strip_prefix('M') {
+        let mut fields = spec.splitn(3, '.');
+        let month   = try!(parse_tz_int(try!(fields.next().ok_or_else(|| PosixTzParseError("missing month in Mm.w.d rule".into()))))) as u8;
+        let week    = try!(parse_tz_int(try!(fields.next().ok_or_else(|| PosixTzParseError("missing week in Mm.w.d rule".into()))))) as u8;
+        let weekday = try!(parse_tz_int(try!(fields.next().ok_or_else(|| PosixTzParseError("missing weekday in Mm.w.d rule".into()))))) as u8;
+
+        if !(1 ..= 12).contains(&month) {
+            return Err(PosixTzParseError(format!("month out of range 1..=12 in Mm.w.d rule, found {}", month)));
+        }
+        if !(1 ..= 5).contains(&week) {
+            return Err(PosixTzParseError(format!("week out of range 1..=5 in Mm.w.d rule, found {}", week)));
+        }
+        if weekday > 6 {
+            return Err(PosixTzParseError(format!("weekday out of range 0..=6 in Mm.w.d rule, found {}", weekday)));
+        }
+
+        PosixTzDay::MonthWeekDay { month: month, week: week, weekday: weekday }
+    }
+    else {
+        PosixTzDay::ZeroBased(try!(parse_tz_int(day_part)) as u16)
+    };
+
+    let time = match time_part {
+        Some(spec)  => try!(take_tz_offset(spec)).0,
+        None        => 2 * 3600,  // Transitions default to 02:00:00 wall-clock time.
+    };
+
+    Ok(PosixTzTransition { day: day, time: time })
+}
+
+fn parse_tz_int(input: &str) -> Result<i32, PosixTzParseError> {
+    input.parse().map_err(|_| PosixTzParseError(format!("expected a number, found {:?}", input)))
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12  => 31,
+        4 | 6 | 9 | 11               => 30,
+        2 if is_leap_year(year)      => 29,
+        2                            => 28,
+        _                            => unreachable!("month out of range 1..=12"),
+    }
+}
+
+/// Days since the Unix epoch (1970-01-01) for the given proleptic
+/// Gregorian calendar date. See Howard Hinnant's `days_from_civil`:
+/// <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;                                 // [0, 399]
+    let mp = (i64::from(month) + 9) % 12;                    // [0, 11]
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;       // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;         // [0, 146096]
+    era * 146_097 + doe - 719_468
+}
+
+/// The proleptic Gregorian calendar year containing the given day (days
+/// since the Unix epoch). The inverse of `days_from_civil`; see Howard
+/// Hinnant's `civil_from_days`:
+/// <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn year_from_days(z: i64) -> i64 {
+    let z = z + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = z - era * 146_097;                                       // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;  // [0, 399]
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);                 // [0, 365]
+    let mp = (5 * doy + 2) / 153;                                      // [0, 11]
+    y + if mp < 10 { 0 } else { 1 }
+}
+
+/// The calendar year containing the given instant (seconds since the Unix
+/// epoch), in UTC.
+fn year_of_timestamp(unix_timestamp: i32) -> i64 {
+    let secs = i64::from(unix_timestamp);
+    let days = if secs >= 0 { secs / 86_400 } else { (secs - 86_399) / 86_400 };
+    year_from_days(days)
+}
+
+/// The Unix day (days since 1970-01-01) of the `week`th `weekday` in the
+/// given month and year, where `week == 5` means "the last such weekday".
+fn nth_weekday_of_month(year: i64, month: u8, week: u8, weekday: u8) -> i64 {
+    let first_day = days_from_civil(year, month, 1);
+    let first_weekday = (((first_day % 7) + 11) % 7) as u8;  // 1970-01-01 was a Thursday (4).
+    let mut day = 1 + (7 + i64::from(weekday) - i64::from(first_weekday)) % 7;
+
+    if week >= 5 {
+        while day + 7 <= i64::from(days_in_month(year, month)) {
+            day += 7;
+        }
+    }
+    else {
+        day += i64::from(week - 1) * 7;
+    }
+
+    days_from_civil(year, month, day as u8)
+}
+
 
 #[derive(PartialEq, Debug, Copy, Clone)]
 pub enum Error {
     OutOfRange,
     SignMismatch,
+
+    /// Returned by `from_zone_name` when given a name that isn't a plain
+    /// relative path within the tz database (a leading `/`, or a `..`
+    /// component).
+    UnsafeZoneName,
+
+    /// Returned by `from_zone_name` when no zoneinfo directory has a file
+    /// for the requested zone.
+    UnknownZone,
+}
+
+impl ::std::fmt::Display for Error {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            Error::OutOfRange      => write!(f, "timezone offset out of range"),
+            Error::SignMismatch    => write!(f, "mismatched sign between hours and minutes"),
+            Error::UnsafeZoneName  => write!(f, "unsafe zone name"),
+            Error::UnknownZone     => write!(f, "unknown zone"),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::OutOfRange      => "timezone offset out of range",
+            Error::SignMismatch    => "mismatched sign between hours and minutes",
+            Error::UnsafeZoneName  => "unsafe zone name",
+            Error::UnknownZone     => "unknown zone",
+        }
+    }
 }
 
 #[derive(PartialEq, Debug, Clone)]
@@ -165,6 +767,11 @@ pub enum ParseError {
     Date(LocalParseError),
     Number(ParseIntError),
     Parse(parse::Error),
+
+    /// Returned by `from_str_permissive` when the input doesn't even match
+    /// the loosened offset grammar (`Z`, or a sign followed by 2 or 4
+    /// digits with an optional `:` before the last two).
+    Malformed(String),
 }
 
 
@@ -232,7 +839,9 @@ impl TimePiece for ZonedDateTime {
 
 #[cfg(test)]
 mod test {
-    use super::TimeZone;
+    use super::{TimeZone, PosixTzRule, LocalResult};
+    use local::{LocalDate, LocalTime, LocalDateTime, Month};
+    use tz::{Transition, LocalTimeType};
 
     #[test]
     fn fixed_seconds() {
@@ -268,4 +877,148 @@ mod test {
     fn fixed_hm_signs_zero() {
         assert!(TimeZone::of_hours_and_minutes(4, 0).is_ok());
     }
+
+    fn offset_seconds(tz: TimeZone) -> i32 {
+        match tz {
+            TimeZone::FixedOffset { offset, .. }  => offset,
+            other                                 => panic!("expected a FixedOffset, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn permissive_zulu() {
+        match TimeZone::from_str_permissive("Z").unwrap() {
+            TimeZone::UTC  => {},
+            other          => panic!("expected UTC, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn permissive_hours_only() {
+        assert_eq!(offset_seconds(TimeZone::from_str_permissive("+09").unwrap()), 9 * 3600);
+    }
+
+    #[test]
+    fn permissive_hours_and_minutes_no_colon() {
+        assert_eq!(offset_seconds(TimeZone::from_str_permissive("+0930").unwrap()), 9 * 3600 + 30 * 60);
+    }
+
+    #[test]
+    fn permissive_hours_and_minutes_with_colon() {
+        assert_eq!(offset_seconds(TimeZone::from_str_permissive("+09:30").unwrap()), 9 * 3600 + 30 * 60);
+    }
+
+    #[test]
+    fn permissive_negative() {
+        assert_eq!(offset_seconds(TimeZone::from_str_permissive("-05:00").unwrap()), -5 * 3600);
+    }
+
+    #[test]
+    fn permissive_malformed() {
+        assert!(TimeZone::from_str_permissive("nope").is_err());
+    }
+
+    #[test]
+    fn utc_renders_as_z() {
+        assert_eq!(TimeZone::UTC.to_string(), "Z");
+    }
+
+    #[test]
+    fn fixed_offset_renders_with_sign() {
+        assert_eq!(TimeZone::of_hours_and_minutes(9, 30).unwrap().to_string(), "+09:30");
+        assert_eq!(TimeZone::of_hours_and_minutes(-5, 0).unwrap().to_string(), "-05:00");
+    }
+
+    #[test]
+    fn unknown_offset_renders_as_negative_zero() {
+        assert_eq!(TimeZone::unknown_offset().to_string(), "-00:00");
+        assert_ne!(TimeZone::unknown_offset().to_string(), TimeZone::UTC.to_string());
+    }
+
+    #[test]
+    fn posix_tz_us_rule() {
+        let rule = PosixTzRule::parse("EST5EDT,M3.2.0,M11.1.0").unwrap();
+
+        assert_eq!(rule.offset_at(2024, 1_705_276_800), -5 * 3600);  // 2024-01-15: standard time (EST)
+        assert_eq!(rule.offset_at(2024, 1_710_053_999), -5 * 3600);  // 2024-03-10 06:59:59 UTC: just before spring forward
+        assert_eq!(rule.offset_at(2024, 1_710_054_000), -4 * 3600);  // 2024-03-10 07:00:00 UTC: just after (EDT)
+        assert_eq!(rule.offset_at(2024, 1_730_613_599), -4 * 3600);  // 2024-11-03 05:59:59 UTC: just before fall back
+        assert_eq!(rule.offset_at(2024, 1_730_613_600), -5 * 3600);  // 2024-11-03 06:00:00 UTC: just after (EST)
+    }
+
+    #[test]
+    fn posix_tz_julian_day() {
+        let rule = PosixTzRule::parse("XXX0YYY,J60,J300").unwrap();
+
+        // J60 never counts February 29th, so it's March 1st in every year,
+        // leap or not.
+        assert_eq!(rule.offset_at(2023, 1_677_636_000 - 1), 0);        // 2023-03-01 01:59:59 UTC (non-leap)
+        assert_eq!(rule.offset_at(2023, 1_677_636_000), 3600);         // 2023-03-01 02:00:00 UTC
+        assert_eq!(rule.offset_at(2024, 1_709_258_400 - 1), 0);        // 2024-03-01 01:59:59 UTC (leap)
+        assert_eq!(rule.offset_at(2024, 1_709_258_400), 3600);         // 2024-03-01 02:00:00 UTC
+    }
+
+    #[test]
+    fn posix_tz_month_week_day_out_of_range() {
+        assert!(PosixTzRule::parse("X0Y,M13.5.0,M1.1.0").is_err());  // month 13 doesn't exist
+        assert!(PosixTzRule::parse("X0Y,M3.6.0,M1.1.0").is_err());   // week 6 doesn't exist
+        assert!(PosixTzRule::parse("X0Y,M3.2.7,M1.1.0").is_err());   // weekday 7 doesn't exist
+    }
+
+    #[test]
+    fn posix_tz_southern_hemisphere_wraps() {
+        // Sydney: DST ("AEDT", +11) runs from the 1st Sunday in October to
+        // the 1st Sunday in April, so within a single calendar year the
+        // "start" transition comes later than the "end" one.
+        let rule = PosixTzRule::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+
+        assert_eq!(rule.offset_at(2024, 1_728_144_000 - 1), 10 * 3600);  // just before 2024-10-06 02:00 local spring forward
+        assert_eq!(rule.offset_at(2024, 1_728_144_000), 11 * 3600);      // just after: AEDT
+        assert_eq!(rule.offset_at(2025, 1_743_868_800 - 1), 11 * 3600);  // just before 2025-04-06 03:00 local fall back
+        assert_eq!(rule.offset_at(2025, 1_743_868_800), 10 * 3600);      // just after: AEST
+        assert_eq!(rule.offset_at(2025, 1_736_899_200), 11 * 3600);      // 2025-01-15: still within the wrapped DST range
+    }
+
+    /// A US Eastern-style zone (EST5EDT) with a handful of real transitions
+    /// around the 2024 DST changes, for exercising `TimeZone::resolve`.
+    fn us_eastern() -> TimeZone {
+        let est = LocalTimeType { offset: -5 * 3600, is_dst: false, name: "EST".to_owned() };
+        let edt = LocalTimeType { offset: -4 * 3600, is_dst: true, name: "EDT".to_owned() };
+
+        TimeZone::VariableOffset {
+            transitions: vec![
+                Transition { timestamp: 1_699_164_000, local_time_type: est.clone() },  // 2023-11-05 06:00 UTC: fall back
+                Transition { timestamp: 1_710_054_000, local_time_type: edt.clone() },  // 2024-03-10 07:00 UTC: spring forward
+                Transition { timestamp: 1_730_613_600, local_time_type: est.clone() },  // 2024-11-03 06:00 UTC: fall back
+            ],
+            posix_rule: PosixTzRule::parse("EST5EDT,M3.2.0,M11.1.0").ok(),
+        }
+    }
+
+    #[test]
+    fn resolve_gap_is_none() {
+        // 2024-03-10 02:30:00 local never happens: clocks jump from 02:00 straight to 03:00.
+        let local = LocalDateTime::new(LocalDate::ymd(2024, Month::March, 10).unwrap(), LocalTime::hms(2, 30, 0).unwrap());
+        assert_eq!(us_eastern().resolve(local), LocalResult::None);
+    }
+
+    #[test]
+    fn resolve_overlap_is_ambiguous() {
+        // 2024-11-03 01:30:00 local happens twice: once in EDT, once after falling back to EST.
+        let local = LocalDateTime::new(LocalDate::ymd(2024, Month::November, 3).unwrap(), LocalTime::hms(1, 30, 0).unwrap());
+        match us_eastern().resolve(local) {
+            LocalResult::Ambiguous(a, b)  => assert!(a != b),
+            other                         => panic!("expected Ambiguous, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn resolve_normal_is_single() {
+        // 2024-06-15 12:00:00 local is an ordinary summer instant: unambiguously EDT.
+        let local = LocalDateTime::new(LocalDate::ymd(2024, Month::June, 15).unwrap(), LocalTime::hms(12, 0, 0).unwrap());
+        match us_eastern().resolve(local) {
+            LocalResult::Single(_)  => {},
+            other                   => panic!("expected Single, got {:?}", other),
+        }
+    }
 }